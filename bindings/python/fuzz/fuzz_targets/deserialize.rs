@@ -0,0 +1,14 @@
+#![no_main]
+
+use libfuzzer_sys::fuzz_target;
+use safetensors_rust::safe_deserialize;
+
+// Coverage-guided harness for the hardened entry point every public
+// deserialize call (`deserialize`, `deserialize_file`, `open_file`)
+// funnels untrusted bytes through. Run with `cargo fuzz run deserialize`.
+//
+// Random bytes must either be rejected with a `PyErr` or parse cleanly;
+// they must never panic or read out of bounds.
+fuzz_target!(|data: &[u8]| {
+    let _ = safe_deserialize(data);
+});
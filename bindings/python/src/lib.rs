@@ -1,110 +1,733 @@
+use memmap::Mmap;
 use memmap::MmapOptions;
+use pyo3::class::buffer::PyBufferProtocol;
 use pyo3::exceptions;
+use pyo3::ffi;
 use pyo3::prelude::*;
-use pyo3::types::{PyByteArray, PyBytes, PyDict, PyList};
+use pyo3::types::{PyByteArray, PyBytes, PyDict, PyList, PyMemoryView, PySlice, PyTuple};
 use safetensors::{Dtype, SafeTensor, SafeTensorBorrowed, Tensor};
 use std::collections::HashMap;
 use std::fs::File;
 use std::io::Write;
+use std::os::raw::c_int;
 
+fn dtype_from_str(value: &str) -> PyResult<Dtype> {
+    Ok(match value {
+        "bool" => Dtype::BOOL,
+        "uint8" => Dtype::U8,
+        "int8" => Dtype::I8,
+        "int16" => Dtype::I16,
+        "uint16" => Dtype::U16,
+        "int32" => Dtype::I32,
+        "uint32" => Dtype::U32,
+        "int64" => Dtype::I64,
+        "uint64" => Dtype::U64,
+        "float16" => Dtype::F16,
+        "bfloat16" => Dtype::BF16,
+        "float32" => Dtype::F32,
+        "float64" => Dtype::F64,
+        dtype_str => {
+            return Err(exceptions::PyException::new_err(format!(
+                "Unknown dtype {}",
+                dtype_str
+            )))
+        }
+    })
+}
+
+/// Owns a buffer-protocol view acquired from an array-like object (e.g. a
+/// NumPy ndarray) for as long as its raw bytes are needed by `serialize`.
+///
+/// Acquired with the raw `ffi::PyObject_GetBuffer` (not `pyo3::buffer::
+/// PyBuffer<u8>`, whose `get` rejects any PEP-3118 `format` that isn't
+/// itself byte-shaped, i.e. every non-`uint8` NumPy dtype) so the bytes are
+/// read regardless of the buffer's declared element format.
+struct BorrowedBuffer {
+    view: ffi::Py_buffer,
+}
+
+impl BorrowedBuffer {
+    fn get(array: &PyAny) -> PyResult<Self> {
+        let mut view: ffi::Py_buffer = unsafe { std::mem::zeroed() };
+        let ret = unsafe {
+            ffi::PyObject_GetBuffer(array.as_ptr(), &mut view, ffi::PyBUF_ND | ffi::PyBUF_FORMAT)
+        };
+        if ret != 0 {
+            return Err(PyErr::fetch(array.py()));
+        }
+        let buffer = Self { view };
+        if unsafe { ffi::PyBuffer_IsContiguous(&buffer.view, b'C' as std::os::raw::c_char) } == 0 {
+            return Err(exceptions::PyValueError::new_err(
+                "Only C-contiguous arrays are supported; call `numpy.ascontiguousarray` first",
+            ));
+        }
+        Ok(buffer)
+    }
+
+    fn as_bytes(&self) -> &[u8] {
+        unsafe { std::slice::from_raw_parts(self.view.buf as *const u8, self.view.len as usize) }
+    }
+}
+
+impl Drop for BorrowedBuffer {
+    fn drop(&mut self) {
+        unsafe { ffi::PyBuffer_Release(&mut self.view) };
+    }
+}
+
+/// Builds a `Tensor` straight from an array-like object's own `shape`,
+/// `dtype` and buffer-protocol bytes, borrowing the data rather than copying
+/// it. The backing buffer is kept alive in `buffers` for as long as `'a`.
+fn array_to_tensor<'a>(buffers: &mut Vec<BorrowedBuffer>, array: &'a PyAny) -> PyResult<Tensor<'a>> {
+    let shape: Vec<usize> = array.getattr("shape")?.extract()?;
+    let dtype_name: String = array.getattr("dtype")?.getattr("name")?.extract()?;
+    let dtype = dtype_from_str(&dtype_name)?;
+
+    let buffer = BorrowedBuffer::get(array)?;
+    let bytes = buffer.as_bytes();
+    // SAFETY: `buffer` is pushed onto `buffers`, which the caller keeps alive
+    // for at least as long as the `Tensor` built from this slice is used.
+    let data: &'a [u8] = unsafe { std::slice::from_raw_parts(bytes.as_ptr(), bytes.len()) };
+    buffers.push(buffer);
+
+    Ok(Tensor::new(data, dtype, shape))
+}
+
+/// Builds a `Tensor` from the legacy `{"shape", "dtype", "data"}` dict form.
+fn dict_to_tensor<'a>(tensor_desc: &'a PyDict) -> PyResult<Tensor<'a>> {
+    let mut shape: Vec<usize> = vec![];
+    let mut dtype = Dtype::F32;
+    let mut data: &[u8] = &[];
+    for (key, value) in tensor_desc {
+        let key: &str = key.extract()?;
+        match key {
+            "shape" => shape = value.extract()?,
+            "dtype" => dtype = dtype_from_str(value.extract()?)?,
+            "data" => data = value.extract()?,
+            _ => println!("Ignored unknown kwarg option {}", key),
+        };
+    }
+    Ok(Tensor::new(data, dtype, shape))
+}
+
+/// Accepts either the legacy `{"shape", "dtype", "data"}` dict per tensor, or
+/// an array-like object (e.g. a NumPy ndarray) whose own `shape`/`dtype` and
+/// buffer-protocol bytes are read directly.
 fn prepare<'a, 'b>(
-    py: Python<'b>,
-    tensor_dict: HashMap<String, &'a PyDict>,
-) -> PyResult<HashMap<String, Tensor<'a>>> {
-    let start = std::time::Instant::now();
+    _py: Python<'b>,
+    tensor_dict: HashMap<String, &'a PyAny>,
+) -> PyResult<(HashMap<String, Tensor<'a>>, Vec<BorrowedBuffer>)> {
     let mut tensors = HashMap::new();
+    let mut buffers = Vec::new();
     for (tensor_name, tensor_desc) in tensor_dict {
-        let mut shape: Vec<usize> = vec![];
-        let mut dtype = Dtype::F32;
-        let mut data: &[u8] = &[];
-        for (key, value) in tensor_desc {
-            let key: &str = key.extract()?;
-            match key {
-                "shape" => shape = value.extract()?,
-                "dtype" => {
-                    let value: &str = value.extract()?;
-                    dtype = match value {
-                        "float32" => Dtype::F32,
-                        "float64" => Dtype::F64,
-                        "int32" => Dtype::I32,
-                        dtype_str => {
-                            unimplemented!("Did not cover this dtype: {}", dtype_str)
-                        }
-                    }
-                }
-                "data" => data = value.extract()?,
-                _ => println!("Ignored unknown kwarg option {}", key),
-            };
-        }
-
-        let tensor = Tensor::new(data, dtype, shape);
+        let tensor = match tensor_desc.downcast::<PyDict>() {
+            Ok(dict) => dict_to_tensor(dict)?,
+            Err(_) => array_to_tensor(&mut buffers, tensor_desc)?,
+        };
         tensors.insert(tensor_name, tensor);
     }
-    Ok(tensors)
+    Ok((tensors, buffers))
+}
+
+fn dtype_to_string(dtype: Dtype) -> PyResult<&'static str> {
+    Ok(match dtype {
+        Dtype::BOOL => "bool",
+        Dtype::U8 => "uint8",
+        Dtype::I8 => "int8",
+        Dtype::I16 => "int16",
+        Dtype::U16 => "uint16",
+        Dtype::I32 => "int32",
+        Dtype::U32 => "uint32",
+        Dtype::I64 => "int64",
+        Dtype::U64 => "uint64",
+        Dtype::F16 => "float16",
+        Dtype::BF16 => "bfloat16",
+        Dtype::F32 => "float32",
+        Dtype::F64 => "float64",
+        dtype => {
+            return Err(exceptions::PyValueError::new_err(format!(
+                "Unsupported dtype {:?}",
+                dtype
+            )))
+        }
+    })
+}
+
+fn element_size(dtype: Dtype) -> PyResult<usize> {
+    Ok(match dtype {
+        Dtype::BOOL | Dtype::U8 | Dtype::I8 => 1,
+        Dtype::I16 | Dtype::U16 | Dtype::F16 | Dtype::BF16 => 2,
+        Dtype::I32 | Dtype::U32 | Dtype::F32 => 4,
+        Dtype::I64 | Dtype::U64 | Dtype::F64 => 8,
+        dtype => {
+            return Err(exceptions::PyValueError::new_err(format!(
+                "Unsupported dtype {:?}",
+                dtype
+            )))
+        }
+    })
+}
+
+/// A tensor's byte range within the buffer it was parsed from (`tensor.data`
+/// is always a sub-slice of that buffer).
+fn tensor_byte_range(bytes: &[u8], tensor_data: &[u8]) -> (usize, usize) {
+    let start = tensor_data.as_ptr() as usize - bytes.as_ptr() as usize;
+    (start, start + tensor_data.len())
 }
 
 #[pyfunction]
+#[pyo3(signature = (tensor_dict, metadata = None))]
 fn serialize<'a, 'b>(
     py: Python<'b>,
-    tensor_dict: HashMap<String, &'a PyDict>,
+    tensor_dict: HashMap<String, &'a PyAny>,
+    metadata: Option<HashMap<String, String>>,
 ) -> PyResult<&'b PyBytes> {
-    let tensors = prepare(py, tensor_dict)?;
-    let out = SafeTensor::serialize(&tensors);
+    let (tensors, _buffers) = prepare(py, tensor_dict)?;
+    let out = SafeTensor::serialize(&tensors, metadata);
     let pybytes = PyBytes::new(py, &out);
     Ok(pybytes)
 }
 
 #[pyfunction]
+#[pyo3(signature = (tensor_dict, filename, metadata = None))]
 fn serialize_file<'a, 'b>(
     py: Python<'b>,
-    tensor_dict: HashMap<String, &'a PyDict>,
+    tensor_dict: HashMap<String, &'a PyAny>,
     filename: &str,
+    metadata: Option<HashMap<String, String>>,
 ) -> PyResult<()> {
-    let tensors = prepare(py, tensor_dict)?;
-    SafeTensor::serialize_to_file(&tensors, filename)?;
+    let (tensors, _buffers) = prepare(py, tensor_dict)?;
+    SafeTensor::serialize_to_file(&tensors, metadata, filename)?;
     Ok(())
 }
 
-#[pyfunction]
-fn deserialize(py: Python, bytes: &[u8]) -> PyResult<Vec<(String, HashMap<String, PyObject>)>> {
-    let start = std::time::Instant::now();
+/// Owns a memory-mapped safetensors file so the mapping stays alive for as
+/// long as any zero-copy `memoryview` handed out from it is still referenced
+/// on the Python side.
+#[pyclass]
+struct MmapStorage {
+    mmap: Mmap,
+}
+
+#[pyproto]
+impl PyBufferProtocol for MmapStorage {
+    fn bf_getbuffer(slf: PyRefMut<Self>, view: *mut ffi::Py_buffer, flags: c_int) -> PyResult<()> {
+        let bytes = &slf.mmap[..];
+        let ret = unsafe {
+            ffi::PyBuffer_FillInfo(
+                view,
+                slf.as_ptr() as *mut _,
+                bytes.as_ptr() as *mut _,
+                bytes.len() as isize,
+                1, // read-only
+                flags,
+            )
+        };
+        if ret == -1 {
+            return Err(PyErr::fetch(slf.py()));
+        }
+        Ok(())
+    }
+
+    fn bf_releasebuffer(_slf: PyRefMut<Self>, _view: *mut ffi::Py_buffer) {}
+}
+
+/// Parses and validates an untrusted safetensors byte buffer, rejecting
+/// anything the format doesn't strictly allow instead of trusting the
+/// underlying parser to have caught it. Checks, beyond what
+/// `SafeTensorBorrowed::deserialize` itself already enforces: the header
+/// length prefix fits within `bytes`, every tensor's byte range lies within
+/// the data segment, tensor ranges are monotonic and non-overlapping, and
+/// each range's length matches `product(shape) * dtype_size`.
+pub fn safe_deserialize(bytes: &[u8]) -> PyResult<SafeTensorBorrowed> {
+    if bytes.len() < 8 {
+        return Err(exceptions::PyValueError::new_err(
+            "Invalid safetensors file: missing header length prefix",
+        ));
+    }
+    let header_len = u64::from_le_bytes(bytes[..8].try_into().unwrap()) as usize;
+    if header_len > bytes.len() - 8 {
+        return Err(exceptions::PyValueError::new_err(
+            "Invalid safetensors file: header length prefix exceeds file size",
+        ));
+    }
+
     let safetensor = SafeTensorBorrowed::deserialize(bytes).map_err(|e| {
-        exceptions::PyException::new_err(format!("Error while deserializing: {:?}", e))
+        exceptions::PyValueError::new_err(format!("Error while deserializing: {:?}", e))
     })?;
+
+    let data_start = 8 + header_len;
+    let mut ranges: Vec<(usize, usize, String)> = Vec::new();
+    for (name, tensor) in safetensor.tensors() {
+        let (start, stop) = tensor_byte_range(bytes, tensor.data);
+        if start < data_start || stop > bytes.len() || start > stop {
+            return Err(exceptions::PyValueError::new_err(format!(
+                "Invalid safetensors file: tensor {:?} offsets lie outside the data segment",
+                name
+            )));
+        }
+        let expected_len = tensor
+            .shape
+            .iter()
+            .try_fold(element_size(tensor.dtype)?, |acc, &dim| acc.checked_mul(dim));
+        if expected_len != Some(stop - start) {
+            return Err(exceptions::PyValueError::new_err(format!(
+                "Invalid safetensors file: tensor {:?} byte range does not match shape/dtype",
+                name
+            )));
+        }
+        ranges.push((start, stop, name));
+    }
+
+    ranges.sort_unstable_by_key(|&(start, _, _)| start);
+    for window in ranges.windows(2) {
+        let (_, prev_stop, prev_name) = &window[0];
+        let (start, _, name) = &window[1];
+        if start < prev_stop {
+            return Err(exceptions::PyValueError::new_err(format!(
+                "Invalid safetensors file: tensors {:?} and {:?} have overlapping byte ranges",
+                prev_name, name
+            )));
+        }
+    }
+
+    Ok(safetensor)
+}
+
+/// Returns `tensor_data` either as a freshly copied `PyByteArray` (`copy`),
+/// or as a `memoryview` borrowing directly from `source` (which must expose
+/// the buffer protocol and must back `bytes`).
+fn raw_bytes_object(
+    py: Python,
+    bytes: &[u8],
+    source: &PyAny,
+    tensor_data: &[u8],
+    copy: bool,
+) -> PyResult<PyObject> {
+    if copy {
+        Ok(PyByteArray::new(py, tensor_data).into())
+    } else {
+        let (start, stop) = tensor_byte_range(bytes, tensor_data);
+        let view = PyMemoryView::try_from(source)?;
+        let slice = PySlice::new(py, start as isize, stop as isize, 1);
+        Ok(view.get_item(slice)?.into())
+    }
+}
+
+/// Returns the dtype argument to pass to `numpy.frombuffer` for `dtype`.
+/// Stock NumPy has no `"bfloat16"` dtype string, so `Dtype::BF16` is instead
+/// resolved through `ml_dtypes.bfloat16`, the de-facto standard dtype object
+/// NumPy/PyTorch interop code uses for that type.
+fn numpy_dtype_arg(py: Python, dtype: Dtype) -> PyResult<PyObject> {
+    match dtype {
+        Dtype::BF16 => {
+            let ml_dtypes = py.import("ml_dtypes").map_err(|_| {
+                exceptions::PyValueError::new_err(
+                    "Reconstructing a bfloat16 tensor as a NumPy array requires the \
+                     `ml_dtypes` package (`pip install ml_dtypes`)",
+                )
+            })?;
+            Ok(ml_dtypes.getattr("bfloat16")?.into())
+        }
+        dtype => Ok(dtype_to_string(dtype)?.into_py(py)),
+    }
+}
+
+/// Builds the `(name, {shape, dtype, data})` items and the `__metadata__`
+/// map for every tensor in `bytes`. When `numpy` is `true`, `data` holds a
+/// NumPy ndarray reconstructed from the tensor's shape, dtype and raw bytes
+/// instead of the raw bytes themselves.
+fn build_items(
+    py: Python,
+    bytes: &[u8],
+    source: &PyAny,
+    copy: bool,
+    numpy: bool,
+) -> PyResult<(Vec<(String, HashMap<String, PyObject>)>, HashMap<String, String>)> {
+    let safetensor = safe_deserialize(bytes)?;
     let mut items = vec![];
 
     for (tensor_name, tensor) in safetensor.tensors() {
         let mut map = HashMap::new();
 
-        let pyshape: PyObject = PyList::new(py, tensor.shape.into_iter()).into();
-        let pydtype: PyObject = format!("{:?}", tensor.dtype).into_py(py);
+        let pyshape: PyObject = PyList::new(py, tensor.shape.clone().into_iter()).into();
+        let pydtype: PyObject = dtype_to_string(tensor.dtype)?.into_py(py);
 
-        let pydata: PyObject = PyByteArray::new(py, tensor.data).into();
+        let raw = raw_bytes_object(py, bytes, source, tensor.data, copy)?;
+        let pydata: PyObject = if numpy {
+            let np = py.import("numpy")?;
+            let array = np.call_method1("frombuffer", (raw, numpy_dtype_arg(py, tensor.dtype)?))?;
+            array.call_method1("reshape", (tensor.shape,))?.into()
+        } else {
+            raw
+        };
 
         map.insert("shape".to_string(), pyshape);
         map.insert("dtype".to_string(), pydtype);
         map.insert("data".to_string(), pydata);
         items.push((tensor_name, map));
     }
-    Ok(items)
+    let metadata = safetensor.metadata().cloned().unwrap_or_default();
+    Ok((items, metadata))
+}
+
+#[pyfunction]
+#[pyo3(signature = (bytes, copy = true, numpy = false))]
+fn deserialize(
+    py: Python,
+    bytes: &PyAny,
+    copy: bool,
+    numpy: bool,
+) -> PyResult<(
+    Vec<(String, HashMap<String, PyObject>)>,
+    HashMap<String, String>,
+)> {
+    let slice: &[u8] = bytes.extract()?;
+    build_items(py, slice, bytes, copy, numpy)
 }
 
 #[pyfunction]
+#[pyo3(signature = (filename, copy = false, numpy = false))]
 fn deserialize_file(
     py: Python,
     filename: &str,
-) -> PyResult<Vec<(String, HashMap<String, PyObject>)>> {
+    copy: bool,
+    numpy: bool,
+) -> PyResult<(
+    Vec<(String, HashMap<String, PyObject>)>,
+    HashMap<String, String>,
+)> {
     let file = File::open(filename)?;
     let mmap = unsafe { MmapOptions::new().map(&file)? };
-    deserialize(py, &mmap)
+    let storage = Py::new(py, MmapStorage { mmap })?;
+    let bytes = &storage.borrow(py).mmap[..] as *const [u8];
+    // SAFETY: `storage` (and the mapping it owns) is kept alive in `py` for
+    // at least as long as the returned memoryviews borrow from it below.
+    let bytes: &[u8] = unsafe { &*bytes };
+    build_items(py, bytes, storage.as_ref(py), copy, numpy)
+}
+
+#[derive(Clone)]
+struct TensorInfo {
+    dtype: Dtype,
+    shape: Vec<usize>,
+    start: usize,
+    stop: usize,
+}
+
+/// The `(start, stop)` element indices a Python slice selects along one
+/// dimension of length `len` (`step` other than 1 is rejected, see below).
+fn slice_range(slice: &PySlice, len: usize) -> PyResult<(usize, usize)> {
+    let indices = slice.indices(len as std::os::raw::c_long)?;
+    if indices.step != 1 {
+        return Err(exceptions::PyValueError::new_err(
+            "Only contiguous slices (step=1) are supported",
+        ));
+    }
+    Ok((indices.start as usize, indices.stop as usize))
+}
+
+/// Splits a per-dimension slice selection (`ranges[dim] = (start, stop)`,
+/// element indices) into the list of contiguous `(start, stop)` byte spans
+/// it reads, relative to the tensor's own data.
+///
+/// Row-major storage means a selection stays a single contiguous span only
+/// when every dimension after the last restricted one is taken in full;
+/// restricting a dimension followed by other restricted/partial dimensions
+/// scatters it into one span per combination of the outer dimensions'
+/// selected indices, same as real safetensors' `SafeSlice` does.
+fn gather_byte_spans(shape: &[usize], ranges: &[(usize, usize)], elem_size: usize) -> Vec<(usize, usize)> {
+    let ndim = shape.len();
+    let mut elem_strides = vec![1usize; ndim];
+    for dim in (0..ndim.saturating_sub(1)).rev() {
+        elem_strides[dim] = elem_strides[dim + 1] * shape[dim + 1];
+    }
+
+    let last_restricted = ranges
+        .iter()
+        .enumerate()
+        .rev()
+        .find(|&(dim, &(start, stop))| start != 0 || stop != shape[dim])
+        .map(|(dim, _)| dim);
+
+    let Some(k) = last_restricted else {
+        let total: usize = shape.iter().product();
+        return vec![(0, total * elem_size)];
+    };
+
+    let run_len = (ranges[k].1 - ranges[k].0) * elem_strides[k];
+    let mut starts = Vec::new();
+    gather_span_starts(0, k, ranges, &elem_strides, 0, &mut starts);
+    starts
+        .into_iter()
+        .map(|start| (start * elem_size, (start + run_len) * elem_size))
+        .collect()
+}
+
+/// Recursively enumerates the flat element offset of one contiguous span per
+/// combination of the selected indices in dimensions `0..k`.
+fn gather_span_starts(
+    dim: usize,
+    k: usize,
+    ranges: &[(usize, usize)],
+    elem_strides: &[usize],
+    elem_offset: usize,
+    starts: &mut Vec<usize>,
+) {
+    if dim == k {
+        starts.push(elem_offset + ranges[k].0 * elem_strides[k]);
+        return;
+    }
+    for i in ranges[dim].0..ranges[dim].1 {
+        gather_span_starts(
+            dim + 1,
+            k,
+            ranges,
+            elem_strides,
+            elem_offset + i * elem_strides[dim],
+            starts,
+        );
+    }
+}
+
+/// A handle onto a single tensor's header metadata, returned by
+/// `SafeOpen::get_slice`. Reading `data` only touches the byte range the
+/// requested slice actually needs.
+#[pyclass]
+struct PySafeSlice {
+    storage: Py<MmapStorage>,
+    info: TensorInfo,
+}
+
+#[pymethods]
+impl PySafeSlice {
+    #[getter]
+    fn shape(&self) -> Vec<usize> {
+        self.info.shape.clone()
+    }
+
+    #[getter]
+    fn dtype(&self) -> PyResult<&'static str> {
+        dtype_to_string(self.info.dtype)
+    }
+
+    /// Supports `start:stop` slicing along each dimension (e.g.
+    /// `tensor_slice[:, 0:8]`). Restricting a later dimension while an earlier
+    /// one selects more than one index scatters the selection across several
+    /// non-contiguous byte spans, so that case is gathered into a fresh copy;
+    /// a selection that stays one contiguous span (e.g. only the leading
+    /// dimension restricted) is returned as a zero-copy `memoryview`.
+    fn __getitem__(&self, py: Python, key: &PyAny) -> PyResult<HashMap<String, PyObject>> {
+        let shape = &self.info.shape;
+        let ndim = shape.len();
+        let items: Vec<&PyAny> = if let Ok(tuple) = key.downcast::<PyTuple>() {
+            tuple.iter().collect()
+        } else {
+            vec![key]
+        };
+        if items.len() > ndim {
+            return Err(exceptions::PyValueError::new_err(
+                "Too many indices for tensor",
+            ));
+        }
+
+        let mut ranges = Vec::with_capacity(ndim);
+        for (dim, size) in shape.iter().enumerate() {
+            match items.get(dim) {
+                Some(item) => {
+                    let slice = item.downcast::<PySlice>().map_err(|_| {
+                        exceptions::PyTypeError::new_err(
+                            "Only slice indexing (e.g. tensor_slice[0:2]) is currently supported",
+                        )
+                    })?;
+                    ranges.push(slice_range(slice, *size)?);
+                }
+                None => ranges.push((0, *size)),
+            }
+        }
+
+        let elem_size = element_size(self.info.dtype)?;
+        let byte_spans = gather_byte_spans(shape, &ranges, elem_size);
+        let new_shape: Vec<usize> = ranges.iter().map(|(s, e)| e - s).collect();
+
+        let data: PyObject = if let [(start, stop)] = byte_spans[..] {
+            let view = PyMemoryView::try_from(self.storage.as_ref(py))?;
+            let slice = PySlice::new(
+                py,
+                (self.info.start + start) as isize,
+                (self.info.start + stop) as isize,
+                1,
+            );
+            view.get_item(slice)?.into()
+        } else {
+            let storage = self.storage.as_ref(py).borrow();
+            let bytes = &storage.mmap[..];
+            let total: usize = byte_spans.iter().map(|(start, stop)| stop - start).sum();
+            let mut gathered = Vec::with_capacity(total);
+            for (start, stop) in byte_spans {
+                gathered.extend_from_slice(&bytes[self.info.start + start..self.info.start + stop]);
+            }
+            PyByteArray::new(py, &gathered).into()
+        };
+
+        let mut map = HashMap::new();
+        map.insert("shape".to_string(), PyList::new(py, new_shape).into());
+        map.insert(
+            "dtype".to_string(),
+            dtype_to_string(self.info.dtype)?.into_py(py),
+        );
+        map.insert("data".to_string(), data);
+        Ok(map)
+    }
+}
+
+/// A lazily-read handle onto a safetensors file, returned by `open_file`.
+/// Only the header is parsed up front; `get_tensor`/`get_slice` read a
+/// single tensor's byte range from the mapping on demand.
+#[pyclass]
+struct SafeOpen {
+    storage: Py<MmapStorage>,
+    infos: HashMap<String, TensorInfo>,
+}
+
+#[pymethods]
+impl SafeOpen {
+    fn keys(&self) -> Vec<String> {
+        self.infos.keys().cloned().collect()
+    }
+
+    fn get_tensor(&self, py: Python, name: &str) -> PyResult<HashMap<String, PyObject>> {
+        let info = self.infos.get(name).ok_or_else(|| {
+            exceptions::PyException::new_err(format!("No tensor named {}", name))
+        })?;
+
+        let mut map = HashMap::new();
+        map.insert(
+            "shape".to_string(),
+            PyList::new(py, info.shape.clone()).into(),
+        );
+        map.insert(
+            "dtype".to_string(),
+            dtype_to_string(info.dtype)?.into_py(py),
+        );
+        let view = PyMemoryView::try_from(self.storage.as_ref(py))?;
+        let slice = PySlice::new(py, info.start as isize, info.stop as isize, 1);
+        map.insert("data".to_string(), view.get_item(slice)?.into());
+        Ok(map)
+    }
+
+    fn get_slice(&self, name: &str) -> PyResult<PySafeSlice> {
+        let info = self.infos.get(name).ok_or_else(|| {
+            exceptions::PyException::new_err(format!("No tensor named {}", name))
+        })?;
+        Ok(PySafeSlice {
+            storage: self.storage.clone(),
+            info: info.clone(),
+        })
+    }
+}
+
+#[pyfunction]
+fn open_file(py: Python, filename: &str) -> PyResult<SafeOpen> {
+    let file = File::open(filename)?;
+    let mmap = unsafe { MmapOptions::new().map(&file)? };
+    let storage = Py::new(py, MmapStorage { mmap })?;
+    let bytes_ptr = &storage.borrow(py).mmap[..] as *const [u8];
+    // SAFETY: `storage` is kept alive inside `SafeOpen` for the lifetime of
+    // the returned handle, so this header-only read stays valid.
+    let bytes: &[u8] = unsafe { &*bytes_ptr };
+
+    let safetensor = safe_deserialize(bytes)?;
+
+    let mut infos = HashMap::new();
+    for (name, tensor) in safetensor.tensors() {
+        let (start, stop) = tensor_byte_range(bytes, tensor.data);
+        infos.insert(
+            name,
+            TensorInfo {
+                dtype: tensor.dtype,
+                shape: tensor.shape.to_vec(),
+                start,
+                stop,
+            },
+        );
+    }
+
+    Ok(SafeOpen { storage, infos })
 }
 
 /// A Python module implemented in Rust.
 #[pymodule]
 fn safetensors_rust(_py: Python, m: &PyModule) -> PyResult<()> {
+    m.add_class::<MmapStorage>()?;
+    m.add_class::<SafeOpen>()?;
+    m.add_class::<PySafeSlice>()?;
     m.add_function(wrap_pyfunction!(serialize, m)?)?;
     m.add_function(wrap_pyfunction!(serialize_file, m)?)?;
     m.add_function(wrap_pyfunction!(deserialize, m)?)?;
     m.add_function(wrap_pyfunction!(deserialize_file, m)?)?;
+    m.add_function(wrap_pyfunction!(open_file, m)?)?;
     Ok(())
-}
\ No newline at end of file
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn numpy_array<'a>(py: Python<'a>, expr: &str) -> &'a PyAny {
+        py.eval(&format!("__import__('numpy').{}", expr), None, None)
+            .unwrap()
+    }
+
+    #[test]
+    fn array_to_tensor_reads_non_uint8_dtypes() {
+        // Regression test: `PyBuffer::<u8>::get` rejects any PEP-3118 format
+        // that isn't itself byte-shaped, which is every NumPy dtype except
+        // `uint8` - `array_to_tensor` must read the raw bytes regardless of
+        // the buffer's declared element format.
+        Python::with_gil(|py| {
+            let array = numpy_array(py, "array([1, 2, 3, 4], dtype='float32')");
+            let mut buffers = Vec::new();
+            let tensor = array_to_tensor(&mut buffers, array).unwrap();
+            assert_eq!(tensor.dtype, Dtype::F32);
+            assert_eq!(tensor.data.len(), 16);
+
+            let array = numpy_array(py, "array([1, 2, 3, 4], dtype='int64')");
+            let mut buffers = Vec::new();
+            let tensor = array_to_tensor(&mut buffers, array).unwrap();
+            assert_eq!(tensor.dtype, Dtype::I64);
+            assert_eq!(tensor.data.len(), 32);
+        });
+    }
+
+    #[test]
+    fn array_to_tensor_rejects_non_contiguous_arrays() {
+        Python::with_gil(|py| {
+            let array = numpy_array(py, "arange(8, dtype='float32')[::2]");
+            let mut buffers = Vec::new();
+            assert!(array_to_tensor(&mut buffers, array).is_err());
+        });
+    }
+
+    #[test]
+    fn dtype_round_trips_through_the_full_matrix() {
+        for name in [
+            "bool", "uint8", "int8", "int16", "uint16", "int32", "uint32", "int64", "uint64",
+            "float16", "bfloat16", "float32", "float64",
+        ] {
+            let dtype = dtype_from_str(name).unwrap();
+            assert_eq!(dtype_to_string(dtype).unwrap(), name);
+        }
+    }
+
+    #[test]
+    fn metadata_round_trips_through_serialize_and_deserialize() {
+        let tensors: HashMap<String, Tensor> = HashMap::new();
+        let mut metadata = HashMap::new();
+        metadata.insert("framework".to_string(), "test".to_string());
+
+        let bytes = SafeTensor::serialize(&tensors, Some(metadata.clone()));
+        let safetensor = safe_deserialize(&bytes).unwrap();
+        assert_eq!(safetensor.metadata(), Some(&metadata));
+    }
+}